@@ -1,4 +1,5 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::collections::HashMap;
 use std::process::{Command, Stdio};
 use std::fs;
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,12 @@ use anyhow::{Context, Result, anyhow, bail};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    #[arg(
+        long,
+        global = true,
+        help = "Print which config layer (default, global, project, env) supplied each value"
+    )]
+    verbose: bool,
 }
 
 #[derive(Parser)]
@@ -29,6 +36,38 @@ struct PickArgs {
     latest: bool,
     #[arg(short, long, help = "Show commits instead of picking")]
     show: bool,
+    #[arg(
+        long,
+        help = "Notify the configured [notify] recipients about the cherry-picked commits"
+    )]
+    notify: bool,
+    #[arg(
+        long = "path",
+        help = "Only keep commits that touch a path matching this glob (repeatable); a pattern ending in '/' matches everything under that directory"
+    )]
+    path: Vec<String>,
+    #[arg(long, help = "Print the diff of each selected commit before confirming")]
+    diff: bool,
+    #[arg(long, help = "Source environment name (defaults to the first declared environment)")]
+    from: Option<String>,
+    #[arg(long, help = "Target environment name (defaults to the second declared environment)")]
+    to: Option<String>,
+}
+
+#[derive(Parser)]
+struct ChangelogArgs {
+    #[arg(long, help = "Override the starting revision (defaults to the card branch of the first declared environment, hml by default)")]
+    from: Option<String>,
+    #[arg(long, help = "Override the ending revision (defaults to the card branch of the second declared environment, prd by default; with only --from given, HEAD if that branch can't be resolved)")]
+    to: Option<String>,
+    #[arg(long, value_enum, help = "Output format (defaults to the configured or markdown format)")]
+    format: Option<ChangelogFormat>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ChangelogFormat {
+    Markdown,
+    Plain,
 }
 
 #[derive(Subcommand)]
@@ -38,6 +77,11 @@ enum Commands {
         long_about = "Start a new card branch.\n\nThis command checks the repository status (unless debug mode is enabled), prompts for a card number, and then creates a new branch following the pattern 'ZUP-<card_number>-prd'."
     )]
     Pick(PickArgs),
+    #[command(
+        about = "Build a grouped changelog from the picked range.",
+        long_about = "Build a grouped changelog from the same hml..prd revision range used by 'chr pick', bucketing commits by their Conventional Commit type (Features, Bug Fixes, Performance, Refactor, Other)."
+    )]
+    Changelog(ChangelogArgs),
     #[command(
         about = "Create or update configuration file",
         long_about = "Create or update configuration file at ~/.config/chr.toml with custom prefix and suffixes."
@@ -54,35 +98,195 @@ struct Config {
     prefix: Option<String>,
     suffix_prd: Option<String>,
     suffix_hml: Option<String>,
+    changelog: Option<ChangelogConfig>,
+    notify: Option<NotifyConfig>,
+    #[serde(rename = "env")]
+    environments: Option<Vec<EnvConfig>>,
+}
+
+/// A named environment in the promotion chain, e.g. `[[env]] name = "hml"
+/// suffix = "-hml"`. The order environments are declared in determines the
+/// default `--from`/`--to` pair used by `chr pick` and `chr changelog`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+struct EnvConfig {
+    name: String,
+    suffix: String,
 }
 
-fn load_config() -> Config {
-    let config_path = dirs::home_dir()
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+struct NotifyConfig {
+    transport: Option<String>,
+    email_from: Option<String>,
+    email_to: Option<Vec<String>>,
+    webhook_url: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+struct ChangelogConfig {
+    format: Option<String>,
+    types: Option<HashMap<String, String>>,
+}
+
+const DEFAULT_CHANGELOG_TYPES: &[(&str, &str)] = &[
+    ("feat", "Features"),
+    ("fix", "Bug Fixes"),
+    ("perf", "Performance"),
+    ("refactor", "Refactor"),
+];
+const OTHER_SECTION: &str = "Other";
+
+fn global_config_path() -> std::path::PathBuf {
+    dirs::home_dir()
         .unwrap_or_default()
         .join(".config")
-        .join("chr.toml");
-    
-    if config_path.exists() {
-        match fs::read_to_string(&config_path) {
-            Ok(contents) => {
-                match toml::from_str(&contents) {
-                    Ok(config) => return config,
-                    Err(e) => eprintln!("Error parsing config file: {}", e),
-                }
+        .join("chr.toml")
+}
+
+/// Walks up from the current directory looking for a `.chr.toml`, stopping
+/// once it passes the git root (inclusive) or reaches the filesystem root.
+fn find_project_config() -> Option<std::path::PathBuf> {
+    let mut dir = std::env::current_dir().ok()?;
+
+    loop {
+        let candidate = dir.join(".chr.toml");
+        if candidate.exists() {
+            return Some(candidate);
+        }
+
+        if dir.join(".git").exists() {
+            return None;
+        }
+
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+fn load_config_file(path: &std::path::Path) -> Option<Config> {
+    if !path.exists() {
+        return None;
+    }
+
+    match fs::read_to_string(path) {
+        Ok(contents) => match toml::from_str(&contents) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                eprintln!("Error parsing config file '{}': {}", path.display(), e);
+                None
+            }
+        },
+        Err(e) => {
+            eprintln!("Error reading config file '{}': {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Resolves the final `Config` by layering, from lowest to highest
+/// precedence: built-in defaults, the global `~/.config/chr.toml`, a
+/// project-local `.chr.toml` (found by walking up to the git root), and
+/// finally the `CHR_PREFIX`/`CHR_SUFFIX_PRD`/`CHR_SUFFIX_HML` env vars.
+fn load_config(verbose: bool) -> Config {
+    let mut config = Config::default();
+    let mut prefix_source = "default";
+    let mut suffix_prd_source = "default";
+    let mut suffix_hml_source = "default";
+
+    if let Some(global) = load_config_file(&global_config_path()) {
+        if global.prefix.is_some() { prefix_source = "global"; }
+        if global.suffix_prd.is_some() { suffix_prd_source = "global"; }
+        if global.suffix_hml.is_some() { suffix_hml_source = "global"; }
+        config = merge_config(config, global);
+    }
+
+    if let Some(project_path) = find_project_config() {
+        if let Some(project) = load_config_file(&project_path) {
+            if project.prefix.is_some() { prefix_source = "project"; }
+            if project.suffix_prd.is_some() { suffix_prd_source = "project"; }
+            if project.suffix_hml.is_some() { suffix_hml_source = "project"; }
+            config = merge_config(config, project);
+        }
+    }
+
+    if let Ok(value) = std::env::var("CHR_PREFIX") {
+        config.prefix = Some(value);
+        prefix_source = "env";
+    }
+    if let Ok(value) = std::env::var("CHR_SUFFIX_PRD") {
+        config.suffix_prd = Some(value);
+        suffix_prd_source = "env";
+    }
+    if let Ok(value) = std::env::var("CHR_SUFFIX_HML") {
+        config.suffix_hml = Some(value);
+        suffix_hml_source = "env";
+    }
+
+    if verbose {
+        eprintln!("config: prefix supplied by {}", prefix_source);
+        eprintln!("config: suffix_prd supplied by {}", suffix_prd_source);
+        eprintln!("config: suffix_hml supplied by {}", suffix_hml_source);
+    }
+
+    config
+}
+
+/// Layers `overlay` on top of `base`, letting any field the overlay sets
+/// take precedence while leaving the base value in place otherwise.
+fn merge_config(base: Config, overlay: Config) -> Config {
+    Config {
+        prefix: overlay.prefix.or(base.prefix),
+        suffix_prd: overlay.suffix_prd.or(base.suffix_prd),
+        suffix_hml: overlay.suffix_hml.or(base.suffix_hml),
+        changelog: overlay.changelog.or(base.changelog),
+        notify: overlay.notify.or(base.notify),
+        environments: overlay.environments.or(base.environments),
+    }
+}
+
+/// Resolves the ordered list of named environments for a `Config`, using
+/// `[[env]]` entries when present and otherwise synthesizing the
+/// traditional two-environment `hml`/`prd` pair from `suffix_hml`/`suffix_prd`
+/// (or their defaults) for backward compatibility.
+fn resolve_environments(config: &Config) -> Vec<EnvConfig> {
+    let mut environments = match &config.environments {
+        Some(environments) if !environments.is_empty() => environments.clone(),
+        _ => vec![
+            EnvConfig {
+                name: "hml".to_string(),
+                suffix: config.suffix_hml.clone().unwrap_or_else(|| DEFAULT_SUFFIX_HML.to_string()),
+            },
+            EnvConfig {
+                name: "prd".to_string(),
+                suffix: config.suffix_prd.clone().unwrap_or_else(|| DEFAULT_SUFFIX_PRD.to_string()),
             },
-            Err(e) => eprintln!("Error reading config file: {}", e),
+        ],
+    };
+
+    // CHR_SUFFIX_HML/CHR_SUFFIX_PRD must win over a custom [[env]] list too,
+    // matching by name, or they silently stop applying once a project opts
+    // into named environments - env still outranks project/global/default.
+    if let Ok(value) = std::env::var("CHR_SUFFIX_HML") {
+        if let Some(env) = environments.iter_mut().find(|e| e.name == "hml") {
+            env.suffix = value;
         }
     }
-    
-    Config::default()
+    if let Ok(value) = std::env::var("CHR_SUFFIX_PRD") {
+        if let Some(env) = environments.iter_mut().find(|e| e.name == "prd") {
+            env.suffix = value;
+        }
+    }
+
+    environments
 }
 
 fn main() {
     let args = Cli::parse();
     
     let result = match args.command {
-        Commands::Pick(pick_args) => pick(pick_args),
-        Commands::Config => create_config(),
+        Commands::Pick(pick_args) => pick(pick_args, args.verbose),
+        Commands::Changelog(changelog_args) => changelog(changelog_args, args.verbose),
+        Commands::Config => create_config(args.verbose),
     };
 
     if let Err(e) = result {
@@ -91,65 +295,179 @@ fn main() {
     }
 }
 
-fn pick(args: PickArgs) -> Result<()> {
-    let config = load_config();
-    let prefix = config.prefix.as_deref().unwrap_or(DEFAULT_PREFIX);
-    let suffix_prd = config.suffix_prd.as_deref().unwrap_or(DEFAULT_SUFFIX_PRD);
-    let suffix_hml = config.suffix_hml.as_deref().unwrap_or(DEFAULT_SUFFIX_HML);
+/// `glob::Pattern` matches the whole path, so a bare directory prefix like
+/// `src/api/` would otherwise match nothing; widen it to `src/api/**` so
+/// directory scoping works the way the `--path` help describes.
+fn normalize_path_glob(pattern: &str) -> String {
+    if pattern.ends_with('/') {
+        format!("{}**", pattern)
+    } else {
+        pattern.to_string()
+    }
+}
+
+/// Returns whether `hash` changed at least one file matching any of the
+/// supplied globs.
+fn commit_touches_paths(hash: &str, patterns: &[String]) -> Result<bool> {
+    let output = Command::new("git")
+        .arg("diff-tree")
+        .arg("--no-commit-id")
+        .arg("--name-only")
+        .arg("-r")
+        .arg(hash)
+        .output()
+        .context(format!("Failed to list changed files for commit '{}'", hash))?;
+
+    let files = String::from_utf8(output.stdout)
+        .context("Failed to parse changed files output")?;
+
+    Ok(files.lines().any(|file| {
+        patterns.iter().any(|pattern| {
+            glob::Pattern::new(&normalize_path_glob(pattern))
+                .map(|compiled| compiled.matches(file))
+                .unwrap_or(false)
+        })
+    }))
+}
+
+/// Prints `git show --stat` followed by the full diff for a single commit,
+/// used to preview exactly what `--diff` is about to replay.
+fn print_commit_diff(hash: &str) -> Result<()> {
+    let stat = Command::new("git")
+        .arg("show")
+        .arg("--stat")
+        .arg(hash)
+        .output()
+        .context(format!("Failed to show stat for commit '{}'", hash))?;
+    print!("{}", String::from_utf8_lossy(&stat.stdout));
+
+    let show = Command::new("git")
+        .arg("show")
+        .arg(hash)
+        .output()
+        .context(format!("Failed to show diff for commit '{}'", hash))?;
+    print!("{}", String::from_utf8_lossy(&show.stdout));
+
+    Ok(())
+}
+
+fn branch_exists(branch: &str) -> Result<bool> {
+    let output = Command::new("git")
+        .arg("rev-parse")
+        .arg("--verify")
+        .arg(branch)
+        .output()
+        .context(format!("Failed to check if branch '{}' exists", branch))?;
+    Ok(output.status.success())
+}
 
+/// Extracts the card number from the current branch, bailing if it
+/// doesn't match the expected `PREFIX<card-number>...` format.
+fn current_card_number(prefix: &str) -> Result<String> {
     let branch_output = Command::new("git")
         .arg("branch")
         .arg("--show-current")
         .output()
         .context("Failed to get current branch name")?;
-    
+
     let branch_name = String::from_utf8(branch_output.stdout)
         .context("Failed to parse branch name")?
         .trim()
         .to_string();
-    
+
     let parts: Vec<&str> = branch_name.split("-").collect();
     if parts.len() < 2 {
-        bail!("Current branch '{}' doesn't match the expected format '{}<card-number>{}'", 
-            branch_name, prefix, suffix_prd);
+        bail!("Current branch '{}' doesn't match the expected format '{}<card-number>...'",
+            branch_name, prefix);
     }
-    
+
     if !branch_name.starts_with(prefix) {
-        bail!("Current branch '{}' doesn't start with the expected prefix '{}'\nExpected format: '{}<card-number>{}'", 
-            branch_name, prefix, prefix, suffix_prd);
+        bail!("Current branch '{}' doesn't start with the expected prefix '{}'",
+            branch_name, prefix);
     }
-    
-    let card_number = parts.get(1).ok_or_else(|| 
+
+    let card_number = parts.get(1).ok_or_else(||
         anyhow!("Could not extract card number from branch name '{}'", branch_name)
     )?;
 
-    let hml_branch = format!("{}{}{}", prefix, card_number, suffix_hml);
-    let prd_branch = format!("{}{}{}", prefix, card_number, suffix_prd);
-
-    let branch_exists = |branch: &str| -> Result<bool> {
-        let output = Command::new("git")
-            .arg("rev-parse")
-            .arg("--verify")
-            .arg(branch)
-            .output()
-            .context(format!("Failed to check if branch '{}' exists", branch))?;
-        Ok(output.status.success())
-    };
-    
-    if !branch_exists(&prd_branch)? {
-        bail!("Production branch '{}' does not exist", prd_branch);
+    Ok(card_number.to_string())
+}
+
+fn env_branch_name(prefix: &str, card_number: &str, env: &EnvConfig) -> String {
+    format!("{}{}{}", prefix, card_number, env.suffix)
+}
+
+/// Resolves a single environment's branch for the current card, bailing if
+/// it doesn't exist. Used when only one endpoint of a range needs
+/// resolving instead of the full `from`/`to` pair.
+fn resolve_env_branch(prefix: &str, env: &EnvConfig) -> Result<String> {
+    let card_number = current_card_number(prefix)?;
+    let branch = env_branch_name(prefix, &card_number, env);
+
+    if !branch_exists(&branch)? {
+        bail!("Branch '{}' for environment '{}' does not exist", branch, env.name);
     }
-    
-    if !branch_exists(&hml_branch)? {
-        bail!("Homologation branch '{}' does not exist", hml_branch);
+
+    Ok(branch)
+}
+
+/// Resolves the card number from the current branch and builds the
+/// `from`/`to` environment branch names for it, bailing if either branch
+/// doesn't exist.
+fn resolve_card_branches(prefix: &str, from_env: &EnvConfig, to_env: &EnvConfig) -> Result<(String, String, String)> {
+    let card_number = current_card_number(prefix)?;
+
+    let from_branch = env_branch_name(prefix, &card_number, from_env);
+    let to_branch = env_branch_name(prefix, &card_number, to_env);
+
+    if !branch_exists(&to_branch)? {
+        bail!("Branch '{}' for environment '{}' does not exist", to_branch, to_env.name);
+    }
+
+    if !branch_exists(&from_branch)? {
+        bail!("Branch '{}' for environment '{}' does not exist", from_branch, from_env.name);
     }
 
+    Ok((card_number, from_branch, to_branch))
+}
+
+/// Picks the `--from`/`--to` environment pair for `pick`/`changelog`,
+/// defaulting to the first two declared environments.
+fn resolve_env_pair<'a>(envs: &'a [EnvConfig], from: &Option<String>, to: &Option<String>) -> Result<(&'a EnvConfig, &'a EnvConfig)> {
+    let find_env = |name: &str| -> Result<&'a EnvConfig> {
+        envs.iter().find(|e| e.name == name)
+            .ok_or_else(|| anyhow!("Unknown environment '{}'", name))
+    };
+
+    let from_env = match from {
+        Some(name) => find_env(name)?,
+        None => envs.first().ok_or_else(|| anyhow!("No environments configured"))?,
+    };
+
+    let to_env = match to {
+        Some(name) => find_env(name)?,
+        None => envs.get(1).unwrap_or(
+            envs.first().ok_or_else(|| anyhow!("No environments configured"))?
+        ),
+    };
+
+    Ok((from_env, to_env))
+}
+
+fn pick(args: PickArgs, verbose: bool) -> Result<()> {
+    let config = load_config(verbose);
+    let prefix = config.prefix.as_deref().unwrap_or(DEFAULT_PREFIX);
+    let envs = resolve_environments(&config);
+    let (from_env, to_env) = resolve_env_pair(&envs, &args.from, &args.to)?;
+
+    let (card_number, from_branch, to_branch) = resolve_card_branches(prefix, from_env, to_env)?;
+
     let commit_count = if args.latest { 100 } else { args.count };
 
     let log_output = Command::new("git")
         .arg("log")
-        .arg(format!("^{}", &hml_branch))
-        .arg(&prd_branch)
+        .arg(format!("^{}", &from_branch))
+        .arg(&to_branch)
         .arg(format!("-{}", commit_count))
         .arg("--format=%h|%an|%s")
         .output()
@@ -176,11 +494,28 @@ fn pick(args: PickArgs) -> Result<()> {
         output.lines().collect()
     };
 
+    let final_lines: Vec<&str> = if args.path.is_empty() {
+        final_lines
+    } else {
+        let mut filtered = Vec::new();
+        for line in final_lines {
+            let parts: Vec<&str> = line.split("|").collect();
+            if let Some(hash) = parts.first() {
+                if commit_touches_paths(hash.trim(), &args.path)? {
+                    filtered.push(line);
+                }
+            }
+        }
+        filtered
+    };
+
     if final_lines.is_empty() {
         if args.latest {
             println!("No commits found for user '{}'", current_user);
+        } else if !args.path.is_empty() {
+            println!("No commits found touching the given path(s) between '{}' and '{}'", &from_branch, &to_branch);
         } else {
-            println!("No commits found between '{}' and '{}'", &hml_branch, &prd_branch);
+            println!("No commits found between '{}' and '{}'", &from_branch, &to_branch);
         }
         return Ok(());
     }
@@ -223,6 +558,12 @@ fn pick(args: PickArgs) -> Result<()> {
         return Ok(());
     }
 
+    if args.diff {
+        for hash in &commit_hashes {
+            print_commit_diff(hash)?;
+        }
+    }
+
     let ques = dialoguer::Confirm::new()
         .with_prompt("Do you want to cherry-pick these commits?")
         .interact()
@@ -257,11 +598,272 @@ fn pick(args: PickArgs) -> Result<()> {
             
         if status.success() {
             println!("Successfully cherry-picked commits");
+
+            if args.notify {
+                let picked_commits: Vec<PickedCommit> = final_lines
+                    .iter()
+                    .filter_map(|line| {
+                        let parts: Vec<&str> = line.split("|").collect();
+                        if parts.len() >= 3 {
+                            Some(PickedCommit {
+                                hash: parts[0].trim().to_string(),
+                                author: parts[1].trim().to_string(),
+                                subject: parts[2].trim().to_string(),
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                let summary = PickSummary {
+                    card_number: card_number.clone(),
+                    source_branch: to_branch.clone(),
+                    target_branch: from_branch.clone(),
+                    commits: picked_commits,
+                };
+
+                if let Err(e) = notify(&config, &summary) {
+                    eprintln!("Warning: failed to send notification: {:#}", e);
+                }
+            }
         } else {
             println!("Cherry-pick operation failed. You may need to resolve conflicts.");
         }
     }
-    
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct PickedCommit {
+    hash: String,
+    author: String,
+    subject: String,
+}
+
+#[derive(Serialize)]
+struct PickSummary {
+    card_number: String,
+    source_branch: String,
+    target_branch: String,
+    commits: Vec<PickedCommit>,
+}
+
+/// Delivers a summary of the cherry-picked commits to the transport
+/// configured under `[notify]`. Delivery errors are returned to the
+/// caller, which is expected to warn rather than abort since the
+/// cherry-pick itself already succeeded.
+fn notify(config: &Config, summary: &PickSummary) -> Result<()> {
+    let notify_config = config.notify.clone().ok_or_else(||
+        anyhow!("No [notify] section found in the configuration file")
+    )?;
+
+    match notify_config.transport.as_deref() {
+        Some("webhook") => send_webhook_notification(&notify_config, summary),
+        Some("email") => send_email_notification(&notify_config, summary),
+        Some(other) => bail!("Unknown notify transport '{}' (expected 'email' or 'webhook')", other),
+        None => bail!("notify.transport is not set in the configuration file"),
+    }
+}
+
+fn send_webhook_notification(notify_config: &NotifyConfig, summary: &PickSummary) -> Result<()> {
+    let url = notify_config.webhook_url.as_deref()
+        .ok_or_else(|| anyhow!("notify.webhook_url is not set in the configuration file"))?;
+
+    let payload = serde_json::to_value(summary)
+        .context("Failed to serialize notification payload")?;
+
+    ureq::post(url)
+        .send_json(payload)
+        .context("Failed to POST notification webhook")?;
+
+    Ok(())
+}
+
+fn send_email_notification(notify_config: &NotifyConfig, summary: &PickSummary) -> Result<()> {
+    let from = notify_config.email_from.as_deref()
+        .ok_or_else(|| anyhow!("notify.email_from is not set in the configuration file"))?;
+    let to = notify_config.email_to.as_ref()
+        .filter(|recipients| !recipients.is_empty())
+        .ok_or_else(|| anyhow!("notify.email_to is not set in the configuration file"))?;
+
+    let mut body = format!(
+        "Cherry-picked {} commit(s) from '{}' into '{}':\n\n",
+        summary.commits.len(), summary.source_branch, summary.target_branch
+    );
+    for commit in &summary.commits {
+        body.push_str(&format!("- {} {} ({})\n", commit.hash, commit.subject, commit.author));
+    }
+
+    let message = format!(
+        "From: {}\r\nTo: {}\r\nSubject: chr: {} - {} commit(s) picked into {}\r\n\r\n{}",
+        from,
+        to.join(", "),
+        summary.card_number,
+        summary.commits.len(),
+        summary.target_branch,
+        body
+    );
+
+    let mut sendmail = Command::new("sendmail")
+        .arg("-t")
+        .stdin(Stdio::piped())
+        .spawn()
+        .context("Failed to spawn sendmail")?;
+
+    {
+        use std::io::Write;
+        let stdin = sendmail.stdin.as_mut()
+            .ok_or_else(|| anyhow!("Failed to open sendmail stdin"))?;
+        stdin.write_all(message.as_bytes())
+            .context("Failed to write email message to sendmail")?;
+    }
+
+    let status = sendmail.wait().context("Failed to wait on sendmail")?;
+    if !status.success() {
+        bail!("sendmail exited with a non-zero status");
+    }
+
+    Ok(())
+}
+
+/// Parses a Conventional Commit subject into its `type` token and the
+/// remaining description, e.g. "feat(api): add endpoint" -> ("feat", "add endpoint").
+/// Returns `None` for the type when the subject isn't Conventional Commit-shaped.
+fn parse_conventional_commit(subject: &str) -> (Option<String>, String) {
+    if let Some(colon_idx) = subject.find(':') {
+        let header = &subject[..colon_idx];
+        let description = subject[colon_idx + 1..].trim().to_string();
+        let type_token = header
+            .split(['(', '!'])
+            .next()
+            .unwrap_or("")
+            .trim();
+
+        if !type_token.is_empty() && type_token.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return (Some(type_token.to_lowercase()), description);
+        }
+    }
+
+    (None, subject.to_string())
+}
+
+fn changelog(args: ChangelogArgs, verbose: bool) -> Result<()> {
+    let config = load_config(verbose);
+    let prefix = config.prefix.as_deref().unwrap_or(DEFAULT_PREFIX);
+
+    // Only the endpoint(s) that weren't overridden get resolved from the
+    // current card's environment branches, so e.g. `--from <rev>` alone
+    // doesn't require a valid PREFIX<card> branch with both envs present.
+    let (from, to) = match (&args.from, &args.to) {
+        (Some(from), Some(to)) => (from.clone(), to.clone()),
+        (Some(from), None) => {
+            let envs = resolve_environments(&config);
+            let (_from_env, to_env) = resolve_env_pair(&envs, &None, &None)?;
+            let to = resolve_env_branch(prefix, to_env).unwrap_or_else(|_| "HEAD".to_string());
+            (from.clone(), to)
+        }
+        (None, to_opt) => {
+            let envs = resolve_environments(&config);
+            let (from_env, to_env) = resolve_env_pair(&envs, &None, &None)?;
+            let from_branch = resolve_env_branch(prefix, from_env)?;
+            let to = match to_opt {
+                Some(to) => to.clone(),
+                None => resolve_env_branch(prefix, to_env)?,
+            };
+            (from_branch, to)
+        }
+    };
+
+    let log_output = Command::new("git")
+        .arg("log")
+        .arg(format!("^{}", &from))
+        .arg(&to)
+        .arg("--format=%h|%s")
+        .output()
+        .context("Failed to execute git log")?;
+
+    if !log_output.status.success() {
+        bail!("Failed to get commit logs. Make sure both '{}' and '{}' exist.", from, to);
+    }
+
+    let output = String::from_utf8(log_output.stdout)
+        .context("Failed to parse git log output")?;
+
+    if output.trim().is_empty() {
+        println!("No commits found between '{}' and '{}'", from, to);
+        return Ok(());
+    }
+
+    let changelog_config = config.changelog.clone().unwrap_or_default();
+
+    let format = args.format.unwrap_or(match changelog_config.format.as_deref() {
+        Some("plain") => ChangelogFormat::Plain,
+        _ => ChangelogFormat::Markdown,
+    });
+
+    let custom_types = changelog_config.types.unwrap_or_default();
+
+    let mut sections: Vec<(String, Vec<String>)> = DEFAULT_CHANGELOG_TYPES
+        .iter()
+        .map(|(_, title)| (title.to_string(), Vec::new()))
+        .collect();
+    sections.push((OTHER_SECTION.to_string(), Vec::new()));
+
+    let section_for_type = |type_token: &str| -> String {
+        if let Some(title) = custom_types.get(type_token) {
+            return title.clone();
+        }
+        DEFAULT_CHANGELOG_TYPES
+            .iter()
+            .find(|(token, _)| *token == type_token)
+            .map(|(_, title)| title.to_string())
+            .unwrap_or_else(|| OTHER_SECTION.to_string())
+    };
+
+    for line in output.lines() {
+        let mut parts = line.splitn(2, '|');
+        let hash = parts.next().unwrap_or("").trim();
+        let subject = parts.next().unwrap_or("").trim();
+
+        let (type_token, description) = parse_conventional_commit(subject);
+        let title = type_token
+            .as_deref()
+            .map(section_for_type)
+            .unwrap_or_else(|| OTHER_SECTION.to_string());
+
+        let entry = format!("{} ({})", description, hash);
+
+        match sections.iter_mut().find(|(section_title, _)| *section_title == title) {
+            Some((_, lines)) => lines.push(entry),
+            None => sections.push((title, vec![entry])),
+        }
+    }
+
+    for (title, lines) in &sections {
+        if lines.is_empty() {
+            continue;
+        }
+
+        match format {
+            ChangelogFormat::Markdown => {
+                println!("## {}\n", title);
+                for line in lines {
+                    println!("- {}", line);
+                }
+                println!();
+            }
+            ChangelogFormat::Plain => {
+                println!("{}:", title);
+                for line in lines {
+                    println!("  - {}", line);
+                }
+                println!();
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -280,7 +882,7 @@ fn get_current_user() -> Result<String> {
     Ok(user)
 }
 
-fn create_config() -> Result<()> {
+fn create_config(verbose: bool) -> Result<()> {
     let config_dir = dirs::home_dir()
         .ok_or_else(|| anyhow!("Failed to determine home directory"))?
         .join(".config");
@@ -293,7 +895,7 @@ fn create_config() -> Result<()> {
         println!("Created directory: {}", config_dir.display());
     }
 
-    let current_config = load_config();
+    let current_config = load_config(verbose);
     
     let prefix: String = Input::new()
         .with_prompt("Enter prefix for branch names")
@@ -312,17 +914,22 @@ fn create_config() -> Result<()> {
         .default(current_config.suffix_hml.unwrap_or_else(|| DEFAULT_SUFFIX_HML.to_string()))
         .interact()
         .context("Failed to get homologation suffix input")?;
-    
+
+    let environments = edit_environments(current_config.environments.unwrap_or_default())?;
+
     let new_config = Config {
         prefix: Some(prefix),
         suffix_prd: Some(suffix_prd),
         suffix_hml: Some(suffix_hml),
+        changelog: current_config.changelog,
+        notify: current_config.notify,
+        environments: if environments.is_empty() { None } else { Some(environments.clone()) },
     };
-    
+
     let toml_string = toml::to_string(&new_config)
         .context("Failed to convert configuration to TOML")?;
-    
-    let config_content = format!(
+
+    let mut config_content = format!(
         "# Configuration file for chr tool\n\
         # Generated by 'chr config' command\n\n\
         # The prefix for branch names (default: \"{}\")\n\
@@ -338,11 +945,84 @@ fn create_config() -> Result<()> {
         DEFAULT_SUFFIX_HML,
         toml_string.lines().find(|l| l.starts_with("suffix_hml")).unwrap_or("suffix_hml = \"\"")
     );
-    
+
+    if !environments.is_empty() {
+        config_content.push_str("\n# Named environments, in promotion order (overrides suffix_prd/suffix_hml above)\n");
+        for env in &environments {
+            config_content.push_str(&format!("[[env]]\nname = \"{}\"\nsuffix = \"{}\"\n\n", env.name, env.suffix));
+        }
+    }
+
     fs::write(&config_path, config_content)
         .context(format!("Failed to write configuration to {}", config_path.display()))?;
-        
+
     println!("Configuration written to {}", config_path.display());
-    
+
     Ok(())
 }
+
+/// Interactively lets the user add or remove `[[env]]` entries, starting
+/// from the environments already present in the config (if any).
+fn edit_environments(mut environments: Vec<EnvConfig>) -> Result<Vec<EnvConfig>> {
+    let customize = dialoguer::Confirm::new()
+        .with_prompt("Customize named environments (beyond the default hml/prd pair)?")
+        .default(!environments.is_empty())
+        .interact()
+        .context("Failed to get environment customization confirmation")?;
+
+    if !customize {
+        return Ok(environments);
+    }
+
+    loop {
+        if environments.is_empty() {
+            println!("No environments configured yet.");
+        } else {
+            println!("Current environments (in promotion order):");
+            for (i, env) in environments.iter().enumerate() {
+                println!("  {}. {} ({})", i + 1, env.name, env.suffix);
+            }
+        }
+
+        let options = ["Add an environment", "Remove an environment", "Done"];
+        let choice = dialoguer::Select::new()
+            .with_prompt("What would you like to do?")
+            .items(&options)
+            .default(0)
+            .interact()
+            .context("Failed to get environment menu choice")?;
+
+        match choice {
+            0 => {
+                let name: String = Input::new()
+                    .with_prompt("Environment name")
+                    .interact()
+                    .context("Failed to get environment name input")?;
+                let suffix: String = Input::new()
+                    .with_prompt("Branch suffix")
+                    .interact()
+                    .context("Failed to get environment suffix input")?;
+                environments.push(EnvConfig { name, suffix });
+            }
+            1 => {
+                if environments.is_empty() {
+                    println!("No environments to remove.");
+                    continue;
+                }
+                let labels: Vec<String> = environments.iter()
+                    .map(|e| format!("{} ({})", e.name, e.suffix))
+                    .collect();
+                let index = dialoguer::Select::new()
+                    .with_prompt("Which environment do you want to remove?")
+                    .items(&labels)
+                    .default(0)
+                    .interact()
+                    .context("Failed to get environment removal choice")?;
+                environments.remove(index);
+            }
+            _ => break,
+        }
+    }
+
+    Ok(environments)
+}